@@ -1,30 +1,440 @@
+use std::collections::HashMap;
+use std::fs;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 use tauri::menu::{Menu, MenuItemBuilder, MenuItemKind, PredefinedMenuItem, Submenu};
 #[cfg(target_os = "macos")]
 use tauri::menu::AboutMetadata;
-use tauri::Manager;
-use tauri::WebviewWindow;
+#[cfg(desktop)]
+use tauri::tray::{TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri::{WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+#[cfg(not(target_os = "macos"))]
+use tauri_plugin_deep_link::DeepLinkExt;
+use tauri_plugin_opener::OpenerExt;
 
-// Store zoom as u64 bits (f64 * 100 as integer for atomic ops)
-static ZOOM_LEVEL: AtomicU64 = AtomicU64::new(100);
+// URL scheme registered for deep links, e.g. `paseo://some/path`.
+const APP_URL_SCHEME: &str = "paseo";
 
-fn get_zoom_factor() -> f64 {
-    ZOOM_LEVEL.load(Ordering::Relaxed) as f64 / 100.0
+const DEFAULT_HELP_URL: &str = "https://github.com/kodymullinsx/paseo";
+
+// The URL the "Help" menu item opens. Kept in managed state (rather than a
+// `const`) and read each time the item is clicked. It's set once, where
+// `setup` manages `HelpConfig::default()`, and nothing changes it afterwards —
+// construct a custom `HelpConfig` there to point it elsewhere.
+struct HelpConfig {
+    url: String,
+}
+
+impl Default for HelpConfig {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_HELP_URL.to_string(),
+        }
+    }
+}
+
+// Per-window zoom factors, keyed by window label. Kept in managed state so
+// each `WebviewWindow` can be zoomed independently.
+#[derive(Default)]
+struct ZoomState(Mutex<HashMap<String, f64>>);
+
+// Bumped every time a zoom level changes; the debounced writer only
+// persists if it's still the latest write when its delay elapses.
+static ZOOM_WRITE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+const ZOOM_FILE_NAME: &str = "zoom.txt";
+const ZOOM_WRITE_DEBOUNCE: Duration = Duration::from_millis(300);
+
+fn zoom_file_path(app: &AppHandle) -> Option<std::path::PathBuf> {
+    let dir = app.path().app_config_dir().ok()?;
+    Some(dir.join(ZOOM_FILE_NAME))
+}
+
+fn load_zoom_factors(app: &AppHandle) -> HashMap<String, f64> {
+    let Some(path) = zoom_file_path(app) else {
+        return HashMap::new();
+    };
+    let Ok(contents) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (label, factor) = line.split_once('=')?;
+            let factor: f64 = factor.trim().parse().ok()?;
+            Some((label.to_string(), factor.clamp(0.5, 3.0)))
+        })
+        .collect()
+}
+
+fn persist_zoom_factors(app: &AppHandle, state: &ZoomState) {
+    let generation = ZOOM_WRITE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    let snapshot: Vec<(String, f64)> = state
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(label, factor)| (label.clone(), *factor))
+        .collect();
+    std::thread::spawn(move || {
+        std::thread::sleep(ZOOM_WRITE_DEBOUNCE);
+        if ZOOM_WRITE_GENERATION.load(Ordering::SeqCst) != generation {
+            // A newer zoom change has superseded this write.
+            return;
+        }
+        let Some(path) = zoom_file_path(&app) else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let contents = snapshot
+            .iter()
+            .map(|(label, factor)| format!("{label}={factor}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let _ = fs::write(path, contents);
+    });
+}
+
+fn get_zoom_factor(state: &ZoomState, label: &str) -> f64 {
+    state
+        .0
+        .lock()
+        .unwrap()
+        .get(label)
+        .copied()
+        .unwrap_or(1.0)
 }
 
-fn set_zoom_factor(webview: &WebviewWindow, factor: f64) {
+fn set_zoom_factor(app: &AppHandle, state: &ZoomState, webview: &WebviewWindow, factor: f64) {
     let clamped = factor.clamp(0.5, 3.0);
-    ZOOM_LEVEL.store((clamped * 100.0) as u64, Ordering::Relaxed);
+    state
+        .0
+        .lock()
+        .unwrap()
+        .insert(webview.label().to_string(), clamped);
     let _ = webview.set_zoom(clamped);
+    persist_zoom_factors(app, state);
+}
+
+/// Resolves the webview window the user is currently looking at, falling
+/// back to "main" if no window reports focus (e.g. right after launch).
+fn focused_webview_window(app: &AppHandle) -> Option<WebviewWindow> {
+    if let Some(window) = app.get_focused_window() {
+        if let Some(webview) = app.get_webview_window(window.label()) {
+            return Some(webview);
+        }
+    }
+    app.get_webview_window("main")
+}
+
+/// Brings the main window (creating it if necessary) to the front and emits
+/// the opened file paths/URLs to the frontend. Shared by the macOS
+/// `RunEvent::Opened` handler and the Windows/Linux single-instance callback,
+/// since both end up needing to forward a launch into a running instance.
+fn forward_opened_paths(app: &AppHandle, paths: Vec<String>) {
+    let window = app.get_webview_window("main").or_else(|| {
+        let window = WebviewWindowBuilder::new(app, "main", WebviewUrl::default())
+            .title("Paseo")
+            .build()
+            .ok()?;
+
+        #[cfg(target_os = "macos")]
+        watch_fullscreen_toolbar_visibility(&window);
+
+        Some(window)
+    });
+
+    if let Some(window) = window {
+        let _ = window.show();
+        let _ = window.set_focus();
+        let _ = window.emit("paseo://opened-paths", paths);
+    }
+}
+
+// Counter used to give each new window opened from the tray a unique label.
+static NEW_WINDOW_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn open_new_window(app: &AppHandle) -> tauri::Result<WebviewWindow> {
+    let id = NEW_WINDOW_COUNTER.fetch_add(1, Ordering::SeqCst) + 1;
+    let builder = WebviewWindowBuilder::new(app, format!("main-{id}"), WebviewUrl::default())
+        .title("Paseo");
+
+    #[cfg(not(debug_assertions))]
+    let builder = {
+        let script = browser_chrome_suppression_script(&ReleaseBehaviorConfig::default());
+        builder.initialization_script(&script)
+    };
+
+    let window = builder.build()?;
+
+    #[cfg(target_os = "macos")]
+    watch_fullscreen_toolbar_visibility(&window);
+
+    #[cfg(all(windows, not(debug_assertions)))]
+    disable_windows_browser_accelerators(&window);
+
+    Ok(window)
+}
+
+// macOS hide/show is driven straight through `NSApplication`, since neither
+// Tauri's window API nor its predefined menu items expose it as a callable
+// action. These are `#[tauri::command]`s so the frontend can trigger
+// app-level visibility (e.g. a "focus mode") in addition to the App menu.
+#[cfg(target_os = "macos")]
+mod macos_app {
+    use cocoa::appkit::NSApplication;
+    use cocoa::base::nil;
+
+    #[tauri::command]
+    pub fn hide_app() {
+        unsafe {
+            let app = cocoa::appkit::NSApp();
+            app.hide_(nil);
+        }
+    }
+
+    #[tauri::command]
+    pub fn show_app() {
+        unsafe {
+            let app = cocoa::appkit::NSApp();
+            app.unhideAllApplications_(nil);
+        }
+    }
+
+    #[tauri::command]
+    pub fn hide_others() {
+        unsafe {
+            let app = cocoa::appkit::NSApp();
+            app.hideOtherApplications_(nil);
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+use macos_app::{hide_app, hide_others, show_app};
+
+// Toggling toolbar visibility isn't exposed through Tauri's window API, so we
+// reach for the underlying `NSWindow` directly via its raw pointer.
+#[cfg(target_os = "macos")]
+trait WindowExt {
+    fn set_toolbar_visible(&self, visible: bool);
+}
+
+#[cfg(target_os = "macos")]
+impl WindowExt for WebviewWindow {
+    fn set_toolbar_visible(&self, visible: bool) {
+        use cocoa::base::id;
+        use objc::{msg_send, sel, sel_impl};
+
+        let Ok(ns_window) = self.ns_window() else {
+            return;
+        };
+        let ns_window = ns_window as id;
+        unsafe {
+            let toolbar: id = msg_send![ns_window, toolbar];
+            if !toolbar.is_null() {
+                let _: () = msg_send![toolbar, setVisible: visible];
+            }
+        }
+    }
+}
+
+// On macOS, entering native fullscreen resizes the window to exactly the
+// current monitor's size; use that as the fullscreen signal since there's no
+// direct "did enter fullscreen" window event wired up here.
+#[cfg(target_os = "macos")]
+fn sync_toolbar_visibility_for_size(window: &WebviewWindow, size: tauri::PhysicalSize<u32>) {
+    let is_fullscreen = window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .map(|monitor| *monitor.size() == size)
+        .unwrap_or(false);
+    window.set_toolbar_visible(!is_fullscreen);
+}
+
+// Wires up fullscreen-aware toolbar visibility for a single window. Called
+// for every window Paseo creates (the main window in `setup`, and any window
+// opened later via `open_new_window`) so none of them end up with stale
+// toolbar chrome after entering native fullscreen.
+#[cfg(target_os = "macos")]
+fn watch_fullscreen_toolbar_visibility(window: &WebviewWindow) {
+    let watched_window = window.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::Resized(size) = event {
+            sync_toolbar_visibility_for_size(&watched_window, *size);
+        }
+    });
+}
+
+// Which browser-style behaviors to suppress in release builds so Paseo feels
+// like a native app rather than a browser. Debug builds keep everything on,
+// since they're useful for development (inspecting elements, reloading, etc).
+// All `true` by default; individual behaviors can be turned back on by
+// constructing a custom config instead of `ReleaseBehaviorConfig::default()`.
+struct ReleaseBehaviorConfig {
+    disable_context_menu: bool,
+    disable_reload_shortcut: bool,
+    disable_find_in_page: bool,
+    disable_text_selection: bool,
+}
+
+impl Default for ReleaseBehaviorConfig {
+    fn default() -> Self {
+        Self {
+            disable_context_menu: true,
+            disable_reload_shortcut: true,
+            disable_find_in_page: true,
+            disable_text_selection: true,
+        }
+    }
+}
+
+#[cfg(not(debug_assertions))]
+fn browser_chrome_suppression_script(config: &ReleaseBehaviorConfig) -> String {
+    let mut script = String::new();
+
+    if config.disable_context_menu {
+        script.push_str("window.addEventListener('contextmenu', (e) => e.preventDefault());");
+    }
+
+    if config.disable_reload_shortcut || config.disable_find_in_page {
+        script.push_str(
+            "window.addEventListener('keydown', (e) => {\
+                const mod = e.metaKey || e.ctrlKey;\
+                if (!mod) return;\
+                const key = e.key.toLowerCase();",
+        );
+        if config.disable_reload_shortcut {
+            script.push_str("if (key === 'r') { e.preventDefault(); return; }");
+        }
+        if config.disable_find_in_page {
+            script.push_str("if (key === 'f') { e.preventDefault(); return; }");
+        }
+        script.push_str("});");
+    }
+
+    if config.disable_text_selection {
+        script.push_str(
+            "document.addEventListener('selectstart', (e) => {\
+                const tag = e.target.tagName;\
+                if (tag !== 'INPUT' && tag !== 'TEXTAREA') e.preventDefault();\
+            });",
+        );
+    }
+
+    script
+}
+
+// Applies the suppression script to a window that was already built (the
+// main window comes from `tauri.conf.json`, so we can't pass an
+// `initialization_script` to its builder). `on_page_load` re-runs the script
+// on every subsequent navigation/reload, since a one-shot `eval` would be
+// wiped out by either.
+#[cfg(not(debug_assertions))]
+fn suppress_browser_chrome(window: &WebviewWindow, config: &ReleaseBehaviorConfig) {
+    let script = browser_chrome_suppression_script(config);
+
+    let _ = window.eval(&script);
+
+    let reload_script = script.clone();
+    window.on_page_load(move |window, _payload| {
+        let _ = window.eval(&reload_script);
+    });
+
+    // WebView2 has one switch for all of its browser accelerator keys, so it's
+    // turned off whenever either the reload or the find shortcut is suppressed;
+    // on Windows, turning only one of those two back on has no effect.
+    #[cfg(windows)]
+    if config.disable_reload_shortcut || config.disable_find_in_page {
+        disable_windows_browser_accelerators(window);
+    }
+}
+
+// On Windows, Ctrl+R/F5/Ctrl+F/F3 are WebView2 "browser accelerator keys"
+// that WebView2 resolves itself before the page's `keydown` listener ever
+// runs, so the JS-only suppression above can't block them there. Disabling
+// them requires reaching into the native WebView2 controller directly.
+#[cfg(all(windows, not(debug_assertions)))]
+fn disable_windows_browser_accelerators(window: &WebviewWindow) {
+    let _ = window.with_webview(|webview| unsafe {
+        let _ = webview
+            .controller()
+            .SetAreBrowserAcceleratorKeysEnabled(false);
+    });
+}
+
+#[cfg(desktop)]
+fn toggle_main_window(window: &WebviewWindow) {
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Builds the tray icon and its context menu. Tray menu clicks are routed
+/// through the same `on_menu_event` dispatch as the main window menu.
+///
+/// Desktop-only: the `tray` module isn't compiled in for mobile targets.
+#[cfg(desktop)]
+fn build_tray(app: &AppHandle) -> tauri::Result<()> {
+    let show = MenuItemBuilder::with_id("tray_show", "Show Paseo").build(app)?;
+    let new_window = MenuItemBuilder::with_id("tray_new_window", "New Window").build(app)?;
+    let quit = MenuItemBuilder::with_id("tray_quit", "Quit").build(app)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+
+    let tray_menu = Menu::with_items(app, &[&show, &new_window, &separator, &quit])?;
+
+    TrayIconBuilder::new()
+        .menu(&tray_menu)
+        .show_menu_on_left_click(false)
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click { button, .. } = event {
+                if button == tauri::tray::MouseButton::Left {
+                    if let Some(window) = tray.app_handle().get_webview_window("main") {
+                        toggle_main_window(&window);
+                    }
+                }
+            }
+        })
+        .build(app)?;
+
+    Ok(())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // Must be the first plugin registered: on Windows/Linux it's what lets a
+        // file-association or `paseo://` launch forward its argv into the
+        // already-running instance instead of spawning a second process.
+        #[cfg(desktop)]
+        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            let paths: Vec<String> = argv.into_iter().skip(1).collect();
+            forward_opened_paths(app, paths);
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_websocket::init())
+        .invoke_handler({
+            #[cfg(target_os = "macos")]
+            {
+                tauri::generate_handler![hide_app, show_app, hide_others]
+            }
+            #[cfg(not(target_os = "macos"))]
+            {
+                tauri::generate_handler![]
+            }
+        })
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -49,6 +459,12 @@ pub fn run() {
                 });
 
                 if let Some(submenu) = app_menu {
+                    // Tauri's default App submenu already ships Hide/Hide Others/Show All as
+                    // `PredefinedMenuItem`s wired to the real NSApplication behavior, and Cmd+H
+                    // keeps working through the responder chain. The `hide_app`/`show_app`/
+                    // `hide_others` commands above exist only so the frontend can trigger the
+                    // same behavior programmatically (e.g. a "focus mode" button).
+
                     // Tauri's default about item sets only `version`, which macOS renders as
                     // "Version <plist short> (<version>)". Set only `short_version` instead.
                     let about_metadata = AboutMetadata {
@@ -106,34 +522,137 @@ pub fn run() {
                 }
             }
 
-            // Non-macOS: default menu doesn't include a View menu, so add it.
+            // Non-macOS: `Menu::default` only ships File/Window/Help, so Edit and View
+            // are missing entirely. Linux in particular needs the Edit predefined items
+            // present for native Cut/Copy/Paste to work (requires libxdo linkage).
             #[cfg(not(target_os = "macos"))]
             {
+                let edit_menu = Submenu::with_items(
+                    app,
+                    "Edit",
+                    true,
+                    &[
+                        &PredefinedMenuItem::undo(app, None)?,
+                        &PredefinedMenuItem::redo(app, None)?,
+                        &PredefinedMenuItem::separator(app)?,
+                        &PredefinedMenuItem::cut(app, None)?,
+                        &PredefinedMenuItem::copy(app, None)?,
+                        &PredefinedMenuItem::paste(app, None)?,
+                        &PredefinedMenuItem::select_all(app, None)?,
+                    ],
+                )?;
+
                 let view_menu =
                     Submenu::with_items(app, "View", true, &[&zoom_in, &zoom_out, &zoom_reset])?;
-                menu.append(&view_menu)?;
+
+                // Insert Edit/View right after File, keep the default Window/Help submenus
+                // that `Menu::default` already provides.
+                menu.insert(&edit_menu, 1)?;
+                menu.insert(&view_menu, 2)?;
+
+                // `Menu::default` already ships a "Help" submenu; augment it with our Help
+                // URL item instead of appending a second "Help" entry.
+                let help_url_item =
+                    MenuItemBuilder::with_id("help_open_docs", "Paseo Help").build(app)?;
+
+                let mut help_submenu: Option<Submenu<_>> = None;
+                for item in menu.items()? {
+                    if let MenuItemKind::Submenu(submenu) = item {
+                        if submenu.text()? == "Help" {
+                            help_submenu = Some(submenu);
+                            break;
+                        }
+                    }
+                }
+
+                if let Some(help) = help_submenu {
+                    help.append(&PredefinedMenuItem::separator(app)?)?;
+                    help.append(&help_url_item)?;
+                } else {
+                    // Fallback: if the default menu ever changes, create a Help menu.
+                    let help_menu = Submenu::with_items(app, "Help", true, &[&help_url_item])?;
+                    menu.append(&help_menu)?;
+                }
             }
 
             app.set_menu(menu)?;
 
             let window = app.get_webview_window("main").unwrap();
-            let window_clone = window.clone();
 
-            app.on_menu_event(move |_app, event| {
+            // Reapply the user's last zoom level for this window before it's shown.
+            let restored = load_zoom_factors(&app.handle().clone());
+            let restored_main = restored.get("main").copied().unwrap_or(1.0);
+            let _ = window.set_zoom(restored_main);
+            app.manage(ZoomState(Mutex::new(restored)));
+            app.manage(HelpConfig::default());
+
+            // macOS/iOS register `CFBundleURLTypes` through `Info.plist` instead.
+            // Windows/Linux have no installer-time registration here, so register
+            // the scheme at runtime (this is what `tauri-plugin-deep-link` expects
+            // for dev builds; packaged builds should also declare it under
+            // `plugins.deep-link.schemes` in `tauri.conf.json`).
+            #[cfg(not(target_os = "macos"))]
+            let _ = app.deep_link().register(APP_URL_SCHEME);
+
+            #[cfg(desktop)]
+            build_tray(app.handle())?;
+
+            #[cfg(target_os = "macos")]
+            watch_fullscreen_toolbar_visibility(&window);
+
+            #[cfg(not(debug_assertions))]
+            suppress_browser_chrome(&window, &ReleaseBehaviorConfig::default());
+
+            let app_handle = app.handle().clone();
+            app.on_menu_event(move |app, event| {
                 let id = event.id().as_ref();
+                if id == "tray_show" {
+                    if let Some(window) = app.get_webview_window("main") {
+                        let _ = window.show();
+                        let _ = window.set_focus();
+                    }
+                    return;
+                } else if id == "tray_new_window" {
+                    let _ = open_new_window(app);
+                    return;
+                } else if id == "tray_quit" {
+                    app.exit(0);
+                    return;
+                } else if id == "help_open_docs" {
+                    let help_url = app.state::<HelpConfig>().url.clone();
+                    let _ = app.opener().open_url(help_url, None::<&str>);
+                    return;
+                }
+
+                let Some(target) = focused_webview_window(app) else {
+                    return;
+                };
+                let state = app.state::<ZoomState>();
                 if id == "zoom_in" {
-                    let current = get_zoom_factor();
-                    set_zoom_factor(&window_clone, current + 0.1);
+                    let current = get_zoom_factor(&state, target.label());
+                    set_zoom_factor(&app_handle, &state, &target, current + 0.1);
                 } else if id == "zoom_out" {
-                    let current = get_zoom_factor();
-                    set_zoom_factor(&window_clone, current - 0.1);
+                    let current = get_zoom_factor(&state, target.label());
+                    set_zoom_factor(&app_handle, &state, &target, current - 0.1);
                 } else if id == "zoom_reset" {
-                    set_zoom_factor(&window_clone, 1.0);
+                    set_zoom_factor(&app_handle, &state, &target, 1.0);
                 }
             });
 
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            // `RunEvent::Opened` only ever fires on macOS/iOS, where the OS hands a
+            // running (or launching) app the file/URL directly — the file types are
+            // registered via `CFBundleDocumentTypes`/`CFBundleURLTypes` in
+            // `Info.plist`, not here. Windows/Linux forwarding is handled instead by
+            // the single-instance callback registered in `run()`, which receives the
+            // second instance's argv.
+            if let tauri::RunEvent::Opened { urls } = event {
+                let paths: Vec<String> = urls.into_iter().map(|url| url.to_string()).collect();
+                forward_opened_paths(app, paths);
+            }
+        });
 }